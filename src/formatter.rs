@@ -0,0 +1,165 @@
+//! Renders an [`Apdex`](crate::Apdex) result into formats consumable by dashboards and CI
+//! test-reporting tools, which tend to speak JSON or JUnit XML rather than the crate's own
+//! Uniform Output.
+
+use crate::Apdex;
+
+/// Renders an [`Apdex`] result into an external reporting format.
+pub trait Formatter {
+    /// Render `apdex` into this formatter's representation.
+    fn format(&self, apdex: &Apdex) -> String;
+}
+
+/// Renders an [`Apdex`] result as a JSON object with
+/// `{threshold, satisfied, tolerating, frustrated, total, score, rating, small_group}` fields.
+#[cfg(feature = "serde")]
+#[derive(Debug, Default)]
+pub struct JsonFormatter;
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct JsonRecord {
+    threshold: f64,
+    satisfied: u64,
+    tolerating: u64,
+    frustrated: u64,
+    total: u64,
+    score: Option<f64>,
+    rating: &'static str,
+    small_group: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<&Apdex> for JsonRecord {
+    fn from(apdex: &Apdex) -> JsonRecord {
+        JsonRecord {
+            threshold: apdex.threshold,
+            satisfied: apdex.satisfied,
+            tolerating: apdex.tolerating,
+            frustrated: apdex.frustrated,
+            total: apdex.total(),
+            score: apdex.score(),
+            rating: apdex.rating_word(),
+            small_group: apdex.small_group(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Formatter for JsonFormatter {
+    fn format(&self, apdex: &Apdex) -> String {
+        serde_json::to_string(&JsonRecord::from(apdex)).expect("Apdex JSON serialization cannot fail")
+    }
+}
+
+/// Renders an [`Apdex`] result as a single JUnit XML `<testcase>`, turning it into a
+/// `<failure>` when `rating_word()` falls below `pass_rating`.
+#[derive(Debug, Clone)]
+pub struct JunitFormatter {
+    /// Name reported for the rendered `<testcase>`.
+    pub test_name: String,
+    /// Lowest rating word (e.g. `"Good"`) that still counts as passing.
+    pub pass_rating: &'static str,
+}
+
+impl JunitFormatter {
+    /// Create new `JunitFormatter` rendering test cases named `test_name`, failing them when the
+    /// Apdex rating falls below `pass_rating`.
+    pub fn new(test_name: impl Into<String>, pass_rating: &'static str) -> JunitFormatter {
+        JunitFormatter {
+            test_name: test_name.into(),
+            pass_rating,
+        }
+    }
+}
+
+/// Ranks a rating `word` by its position in `bands` (ordered best to worst), so pass/fail
+/// thresholds compare correctly under any `ApdexConfig`, not just the default bands. A word not
+/// found in `bands` (e.g. `"NoSample"`) ranks below every configured band.
+fn rating_rank(bands: &[crate::RatingBand], word: &str) -> usize {
+    bands.iter().position(|band| band.word == word).unwrap_or(bands.len())
+}
+
+impl Formatter for JunitFormatter {
+    fn format(&self, apdex: &Apdex) -> String {
+        let rating = apdex.rating_word();
+        let time = apdex.score().unwrap_or(0.0);
+        let bands = apdex.config.bands();
+
+        if rating_rank(bands, rating) > rating_rank(bands, self.pass_rating) {
+            format!(
+                "<testcase name=\"{}\" time=\"{:.2}\"><failure message=\"Apdex rating {} is below required {}\"/></testcase>",
+                self.test_name, time, rating, self.pass_rating
+            )
+        } else {
+            format!("<testcase name=\"{}\" time=\"{:.2}\"/>", self.test_name, time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn junit_passing_rating_renders_plain_testcase() {
+        let mut apdex = Apdex::default();
+        for _i in 0..100 {
+            apdex.insert(Ok(0.1));
+        }
+        let formatter = JunitFormatter::new("homepage", "Good");
+        assert_eq!(formatter.format(&apdex), "<testcase name=\"homepage\" time=\"1.00\"/>");
+    }
+
+    #[test]
+    fn junit_failing_rating_renders_failure() {
+        let mut apdex = Apdex::new(1.0);
+        for _i in 0..100 {
+            apdex.insert(Ok(5.0));
+        }
+        let formatter = JunitFormatter::new("homepage", "Good");
+        assert_eq!(
+            formatter.format(&apdex),
+            "<testcase name=\"homepage\" time=\"0.00\"><failure message=\"Apdex rating Unacceptable is below required Good\"/></testcase>"
+        );
+    }
+
+    #[cfg(feature = "yansi")]
+    fn band(min_score: f64, word: &'static str) -> crate::RatingBand {
+        crate::RatingBand::new(min_score, word, crate::yansi::Color::Unset)
+    }
+
+    #[cfg(not(feature = "yansi"))]
+    fn band(min_score: f64, word: &'static str) -> crate::RatingBand {
+        crate::RatingBand::new(min_score, word)
+    }
+
+    #[test]
+    fn junit_failing_rating_with_custom_bands_renders_failure() {
+        let bands = vec![band(0.90, "Great"), band(0.0, "Meh")];
+        let config = crate::ApdexConfig::new(4.0, bands);
+        let mut apdex = Apdex::with_config(1.0, config);
+        for _i in 0..100 {
+            apdex.insert(Ok(5.0));
+        }
+        assert_eq!(apdex.rating_word(), "Meh");
+
+        let formatter = JunitFormatter::new("custom", "Great");
+        assert_eq!(
+            formatter.format(&apdex),
+            "<testcase name=\"custom\" time=\"0.00\"><failure message=\"Apdex rating Meh is below required Great\"/></testcase>"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_formatter_emits_expected_fields() {
+        let mut apdex = Apdex::new(1.0);
+        apdex.insert(Ok(0.1));
+        let json = JsonFormatter.format(&apdex);
+        assert_eq!(
+            json,
+            "{\"threshold\":1.0,\"satisfied\":1,\"tolerating\":0,\"frustrated\":0,\"total\":1,\"score\":1.0,\"rating\":\"Excellent\",\"small_group\":true}"
+        );
+    }
+}
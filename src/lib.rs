@@ -8,6 +8,45 @@ pub extern crate yansi;
 use yansi::Color;
 use std::fmt;
 
+mod formatter;
+pub use formatter::Formatter;
+#[cfg(feature = "serde")]
+pub use formatter::JsonFormatter;
+pub use formatter::JunitFormatter;
+
+/// Approximates the quantile function (inverse CDF) of the standard normal distribution at `p`
+/// using Peter Acklam's rational approximation, good to about 1.15e-9 relative error.
+/// Used to turn a confidence level (e.g. 0.95) into a `z` score for confidence intervals.
+fn normal_quantile(p: f64) -> f64 {
+    // Coefficients from Peter J. Acklam's algorithm, as commonly reproduced for this approximation.
+    const A: [f64; 6] = [-3.969683028665376e+01, 2.209460984245205e+02, -2.759285104469687e+02, 1.383_577_518_672_69e2, -3.066479806614716e+01, 2.506628277459239e+00];
+    const B: [f64; 5] = [-5.447609879822406e+01, 1.615858368580409e+02, -1.556989798598866e+02, 6.680131188771972e+01, -1.328068155288572e+01];
+    const C: [f64; 6] = [-7.784894002430293e-03, -3.223964580411365e-01, -2.400758277161838e+00, -2.549732539343734e+00, 4.374664141464968e+00, 2.938163982698783e+00];
+    const D: [f64; 4] = [7.784695709041462e-03, 3.224671290700398e-01, 2.445134137142996e+00, 3.754408661907416e+00];
+
+    const P_LOW: f64 = 0.02425;
+    let p_high = 1.0 - P_LOW;
+
+    if p <= 0.0 {
+        f64::NEG_INFINITY
+    } else if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= p_high {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else if p < 1.0 {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else {
+        f64::INFINITY
+    }
+}
+
 /// Represents Apdex score after samples were characterize into one of the three groups.
 /// When displayed a Uniform Output will be used.
 #[derive(Debug)]
@@ -20,11 +59,150 @@ pub struct Apdex {
     pub tolerating: u64,
     /// Count of response times characterized as Frustrated.
     pub frustrated: u64,
+    /// Tolerating multiplier and rating bands this value was characterized and rated against.
+    pub config: ApdexConfig,
+}
+
+/// One rating band: the score a result must reach (`min_score`) to be called `word`, and the
+/// [`Color`] used to highlight it when the `yansi` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct RatingBand {
+    /// Minimum score (inclusive) a result must reach to fall into this band.
+    pub min_score: f64,
+    /// Rating word reported for scores in this band.
+    pub word: &'static str,
+    /// Color reported for scores in this band.
+    #[cfg(feature = "yansi")]
+    pub color: Color,
+}
+
+impl RatingBand {
+    /// Create a new rating band.
+    #[cfg(feature = "yansi")]
+    pub fn new(min_score: f64, word: &'static str, color: Color) -> RatingBand {
+        RatingBand { min_score, word, color }
+    }
+
+    /// Create a new rating band.
+    #[cfg(not(feature = "yansi"))]
+    pub fn new(min_score: f64, word: &'static str) -> RatingBand {
+        RatingBand { min_score, word }
+    }
+}
+
+#[cfg(feature = "yansi")]
+fn default_bands() -> Vec<RatingBand> {
+    vec![
+        RatingBand::new(0.94, "Excellent", Color::Cyan),
+        RatingBand::new(0.85, "Good", Color::Green),
+        RatingBand::new(0.70, "Fair", Color::Purple),
+        RatingBand::new(0.50, "Poor", Color::Red),
+        RatingBand::new(0.0, "Unacceptable", Color::Red),
+    ]
+}
+
+#[cfg(not(feature = "yansi"))]
+fn default_bands() -> Vec<RatingBand> {
+    vec![
+        RatingBand::new(0.94, "Excellent"),
+        RatingBand::new(0.85, "Good"),
+        RatingBand::new(0.70, "Fair"),
+        RatingBand::new(0.50, "Poor"),
+        RatingBand::new(0.0, "Unacceptable"),
+    ]
+}
+
+/// SLA policy controlling the Tolerating zone multiplier (the Apdex spec's tool-specific
+/// Frustrated multiplier) and the rating bands consulted by [`Apdex::rating_word`] and
+/// [`Apdex::color`].
+#[derive(Debug, Clone)]
+pub struct ApdexConfig {
+    tolerating_multiplier: f64,
+    bands: Vec<RatingBand>,
+}
+
+impl Default for ApdexConfig {
+    fn default() -> ApdexConfig {
+        ApdexConfig::new(4.0, default_bands())
+    }
+}
+
+impl ApdexConfig {
+    /// Create a new config with the given Tolerating zone `tolerating_multiplier` and `bands`,
+    /// ordered from highest to lowest `min_score`.
+    ///
+    /// # Panics
+    /// In debug builds, panics if `tolerating_multiplier` is not greater than `1.0`, or if
+    /// `bands` is not ordered with strictly decreasing `min_score`.
+    pub fn new(tolerating_multiplier: f64, bands: Vec<RatingBand>) -> ApdexConfig {
+        debug_assert!(tolerating_multiplier > 1.0, "tolerating_multiplier must be greater than 1.0");
+        debug_assert!(
+            bands.windows(2).all(|pair| pair[0].min_score > pair[1].min_score),
+            "rating bands must be ordered with strictly decreasing min_score"
+        );
+        ApdexConfig { tolerating_multiplier, bands }
+    }
+
+    /// Tolerating Zone multiplier: a response time up to `threshold * tolerating_multiplier` is
+    /// characterized as Tolerating rather than Frustrated.
+    pub fn tolerating_multiplier(&self) -> f64 {
+        self.tolerating_multiplier
+    }
+
+    /// Rating bands, ordered from highest to lowest `min_score`.
+    pub fn bands(&self) -> &[RatingBand] {
+        &self.bands
+    }
 }
 
 /// Implements Display for the rating output.
 pub struct ApdexRating<'i>(&'i Apdex);
 
+/// Outcome of comparing an Apdex measurement against a prior baseline via [`Apdex::compare`].
+/// A change is only reported as `Regression`/`Improvement` when the two measurements' 95%
+/// confidence intervals do not overlap; otherwise the difference is treated as noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    /// Score is significantly lower than the baseline.
+    Regression {
+        /// Signed score delta (new score minus baseline score).
+        delta: f64,
+        /// Baseline rating word.
+        from: &'static str,
+        /// New rating word.
+        to: &'static str,
+    },
+    /// Score is significantly higher than the baseline.
+    Improvement {
+        /// Signed score delta (new score minus baseline score).
+        delta: f64,
+        /// Baseline rating word.
+        from: &'static str,
+        /// New rating word.
+        to: &'static str,
+    },
+    /// Confidence intervals overlap; the difference is not statistically significant.
+    NoChange {
+        /// Signed score delta (new score minus baseline score).
+        delta: f64,
+        /// Baseline rating word.
+        from: &'static str,
+        /// New rating word.
+        to: &'static str,
+    },
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (label, delta, from, to) = match self {
+            Comparison::Regression { delta, from, to } => ("Regression", delta, from, to),
+            Comparison::Improvement { delta, from, to } => ("Improvement", delta, from, to),
+            Comparison::NoChange { delta, from, to } => ("NoChange", delta, from, to),
+        };
+        write!(f, "{}: {:+.2} (\"{}\" → \"{}\")", label, delta, from, to)
+    }
+}
+
 impl Default for Apdex {
     fn default() -> Apdex {
         Apdex::new(4.0)
@@ -34,11 +212,18 @@ impl Default for Apdex {
 impl Apdex {
     /// Crate new Apdex value given Satisfied Zone/Tolerating Zone threshold time in seconds.
     pub fn new(threshold: f64) -> Apdex {
+        Apdex::with_config(threshold, ApdexConfig::default())
+    }
+
+    /// Crate new Apdex value given Satisfied Zone/Tolerating Zone threshold time in seconds and
+    /// a custom tolerating multiplier/rating bands policy.
+    pub fn with_config(threshold: f64, config: ApdexConfig) -> Apdex {
         Apdex {
             threshold,
             satisfied: 0,
             tolerating: 0,
             frustrated: 0,
+            config,
         }
     }
 
@@ -70,7 +255,7 @@ impl Apdex {
         if let Ok(response_time) = response_time {
             if response_time <= self.threshold {
                 self.satisfied += 1;
-            } else if response_time <= self.threshold * 4.0 {
+            } else if response_time <= self.threshold * self.config.tolerating_multiplier() {
                 self.tolerating += 1;
             } else {
                 self.frustrated += 1;
@@ -112,26 +297,84 @@ impl Apdex {
         ApdexRating(&self)
     }
 
-    /// Returns the rating word: Excellent, Good, Fair, Poor, Unacceptable or NoSample
+    /// Standard error of the Apdex score, estimated from the satisfied/tolerating/frustrated
+    /// counts alone (no raw samples required).
+    ///
+    /// Each sample is treated as a score weight `w ∈ {1.0, 0.5, 0.0}` for
+    /// satisfied/tolerating/frustrated, of which the score is the mean. `E[w²]` follows from the
+    /// counts, the variance is `E[w²] − score²`, and the standard error is `sqrt(variance / n)`.
+    /// Returns `None` if no samples were characterized.
+    pub fn standard_error(&self) -> Option<f64> {
+        if self.no_samples() {
+            return None;
+        }
+        let n = self.total() as f64;
+        let score = self.score()?;
+        let mean_of_weights_squared = (self.satisfied as f64 + self.tolerating as f64 * 0.25) / n;
+        let variance = (mean_of_weights_squared - score * score).max(0.0);
+        Some((variance / n).sqrt())
+    }
+
+    /// Two-sided confidence interval around the Apdex score for the given `confidence`
+    /// (e.g. `0.95` for a 95% interval), clamped to `[0.0, 1.0]`.
+    /// Returns `None` if no samples were characterized.
+    pub fn confidence_interval(&self, confidence: f64) -> Option<(f64, f64)> {
+        let score = self.score()?;
+        let standard_error = self.standard_error()?;
+        let z = normal_quantile(0.5 + confidence / 2.0);
+        let margin = z * standard_error;
+        Some(((score - margin).max(0.0), (score + margin).min(1.0)))
+    }
+
+    /// Compares this (new) measurement against a `baseline`, reporting whether it is a
+    /// statistically significant [`Comparison::Regression`] or [`Comparison::Improvement`], or
+    /// [`Comparison::NoChange`] if the 95% confidence intervals of the two scores overlap.
+    /// Falls back to `NoChange` if either measurement has no samples, since significance cannot
+    /// be established.
+    pub fn compare(&self, baseline: &Apdex) -> Comparison {
+        let delta = match (self.score(), baseline.score()) {
+            (Some(new), Some(base)) => new - base,
+            _ => 0.0,
+        };
+        let from = baseline.rating_word();
+        let to = self.rating_word();
+
+        let significant = match (self.confidence_interval(0.95), baseline.confidence_interval(0.95)) {
+            (Some((new_lo, new_hi)), Some((base_lo, base_hi))) => {
+                if new_lo > base_hi {
+                    Some(true)
+                } else if new_hi < base_lo {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        match significant {
+            Some(true) => Comparison::Improvement { delta, from, to },
+            Some(false) => Comparison::Regression { delta, from, to },
+            None => Comparison::NoChange { delta, from, to },
+        }
+    }
+
+    /// Returns the rating word of the first of this value's [`ApdexConfig`] bands whose
+    /// `min_score` the score reaches (with the default bands: Excellent, Good, Fair, Poor or
+    /// Unacceptable), Unacceptable if no band matches, or NoSample if there are no samples.
     pub fn rating_word(&self) -> &'static str {
         if let Some(score) = self.score() {
-            if score >= 0.94 {
-                "Excellent"
-            } else if score >= 0.85 {
-                "Good"
-            } else if score >= 0.70 {
-                "Fair"
-            } else if score >= 0.50 {
-                "Poor"
-            } else {
-                "Unacceptable"
-            }
+            self.config.bands().iter()
+                .find(|band| score >= band.min_score)
+                .map(|band| band.word)
+                .unwrap_or("Unacceptable")
         } else {
             "NoSample"
         }
     }
 
-    /// Returns [Color](https://docs.rs/yansi/0.4.0/yansi/enum.Color.html) value from [yansi](https://docs.rs/yansi/0.4.0/yansi) crate corresponding to score value
+    /// Returns [Color](https://docs.rs/yansi/0.4.0/yansi/enum.Color.html) value from [yansi](https://docs.rs/yansi/0.4.0/yansi) crate corresponding to score value, as configured by
+    /// this value's [`ApdexConfig`] bands.
     #[cfg(feature = "yansi")]
     pub fn color(&self) -> Color {
         if let Some(score) = self.score() {
@@ -139,20 +382,33 @@ impl Apdex {
                 return Color::Unset
             }
 
-            if score >= 0.94 {
-                Color::Cyan
-            } else if score >= 0.85 {
-                Color::Green
-            } else if score >= 0.70 {
-                Color::Purple
-            } else {
-                Color::Red
-            }
+            self.config.bands().iter()
+                .find(|band| score >= band.min_score)
+                .map(|band| band.color)
+                .unwrap_or(Color::Unset)
         } else {
             return Color::Unset
         }
     }
 
+    /// Accumulates the satisfied/tolerating/frustrated counts of `other` into `self`, for
+    /// folding per-worker or per-shard `Apdex` values into a combined score.
+    ///
+    /// Merging samples bucketed against a different threshold or tolerating multiplier is
+    /// meaningless, so in debug builds this panics if `self.threshold` or
+    /// `self.config.tolerating_multiplier()` differ from `other`'s; in release builds the
+    /// mismatch is not checked and the counts are merged regardless.
+    pub fn merge(&mut self, other: &Apdex) {
+        debug_assert_eq!(self.threshold, other.threshold, "cannot merge Apdex values with different thresholds");
+        debug_assert_eq!(
+            self.config.tolerating_multiplier(), other.config.tolerating_multiplier(),
+            "cannot merge Apdex values with different tolerating multipliers"
+        );
+        self.satisfied += other.satisfied;
+        self.tolerating += other.tolerating;
+        self.frustrated += other.frustrated;
+    }
+
     fn write_threshold(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let low_sample_indicator = if self.small_group() {
             "*"
@@ -168,6 +424,108 @@ impl Apdex {
     }
 }
 
+/// Apdex companion that retains the raw `Ok` response times alongside the usual counts, so
+/// that in addition to the score callers can also report distribution statistics (mean,
+/// percentiles, ...) that operators commonly page on.
+/// `Err` samples are still counted as Frustrated but, having no time value, are not retained.
+/// Streaming users who only need the score should keep using [`Apdex`], which pays no memory
+/// cost for the retained samples.
+#[derive(Debug)]
+pub struct ApdexSummary {
+    /// Underlying sample counts and score.
+    pub apdex: Apdex,
+    samples: Vec<f64>,
+}
+
+impl ApdexSummary {
+    /// Create new, empty `ApdexSummary` given Satisfied Zone/Tolerating Zone threshold time in seconds.
+    pub fn new(threshold: f64) -> ApdexSummary {
+        ApdexSummary {
+            apdex: Apdex::new(threshold),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Create new `ApdexSummary` with samples characterized and retained from provided sample set.
+    /// `Err` samples are counted as Frustrated samples but are not retained.
+    pub fn with_samples(threshold: f64, response_times: impl IntoIterator<Item = Result<f64, ()>>) -> ApdexSummary {
+        response_times.into_iter().fold(Self::new(threshold), |mut summary, response_time| {
+            summary.insert(response_time);
+        summary})
+    }
+
+    /// Characterize given sample, retaining it if it is `Ok`.
+    /// `Err` samples are counted as Frustrated samples but are not retained.
+    pub fn insert(&mut self, response_time: Result<f64, ()>) {
+        if let Ok(response_time) = response_time {
+            self.samples.push(response_time);
+        }
+        self.apdex.insert(response_time);
+    }
+
+    /// Arithmetic mean of the retained response times.
+    /// Returns `None` if no `Ok` samples were characterized.
+    pub fn mean(&self) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().sum::<f64>() / self.samples.len() as f64)
+    }
+
+    /// Standard deviation of the retained response times, computed in one pass as
+    /// `sqrt(Σx²/n − mean²)`.
+    /// Returns `None` if fewer than two `Ok` samples were characterized.
+    pub fn std_dev(&self) -> Option<f64> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        let n = self.samples.len() as f64;
+        let mean = self.mean()?;
+        let mean_of_squares = self.samples.iter().map(|x| x * x).sum::<f64>() / n;
+        Some((mean_of_squares - mean * mean).max(0.0).sqrt())
+    }
+
+    /// Smallest retained response time.
+    /// Returns `None` if no `Ok` samples were characterized.
+    pub fn min(&self) -> Option<f64> {
+        self.samples.iter().cloned().fold(None, |min, x| {
+            Some(min.map_or(x, |min: f64| min.min(x)))
+        })
+    }
+
+    /// Largest retained response time.
+    /// Returns `None` if no `Ok` samples were characterized.
+    pub fn max(&self) -> Option<f64> {
+        self.samples.iter().cloned().fold(None, |max, x| {
+            Some(max.map_or(x, |max: f64| max.max(x)))
+        })
+    }
+
+    /// Median (50th percentile) of the retained response times.
+    /// Returns `None` if no `Ok` samples were characterized.
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(0.5)
+    }
+
+    /// Response time below which `q` (clamped to `[0.0, 1.0]`) of the retained samples fall.
+    /// Sorts the retained samples and linearly interpolates between the two samples closest to
+    /// the fractional rank `h = q * (n - 1)`.
+    /// Returns `None` if no `Ok` samples were characterized.
+    pub fn percentile(&self, q: f64) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let q = q.clamp(0.0, 1.0);
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).expect("response time sample is NaN"));
+
+        let h = q * (sorted.len() - 1) as f64;
+        let lo = sorted[h.floor() as usize];
+        let hi = sorted[h.ceil() as usize];
+        Some(lo + (h - h.floor()) * (hi - lo))
+    }
+}
+
 impl fmt::Display for Apdex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(score) = self.score() {
@@ -186,6 +544,37 @@ impl<'i> fmt::Display for ApdexRating<'i> {
     }
 }
 
+impl std::ops::Add for Apdex {
+    type Output = Apdex;
+
+    fn add(mut self, other: Apdex) -> Apdex {
+        self.merge(&other);
+        self
+    }
+}
+
+impl std::ops::AddAssign<&Apdex> for Apdex {
+    fn add_assign(&mut self, other: &Apdex) {
+        self.merge(other);
+    }
+}
+
+impl std::iter::FromIterator<Apdex> for Apdex {
+    /// Folds an iterator of per-worker/per-shard `Apdex` values into one combined `Apdex` via
+    /// [`Apdex::merge`].
+    ///
+    /// # Panics
+    /// Panics if the iterator is empty, since there is no threshold to adopt for the result.
+    fn from_iter<I: IntoIterator<Item = Apdex>>(iter: I) -> Apdex {
+        let mut iter = iter.into_iter();
+        let mut combined = iter.next().expect("cannot collect an empty iterator of Apdex values");
+        for apdex in iter {
+            combined.merge(&apdex);
+        }
+        combined
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,4 +669,265 @@ mod tests {
         }
         assert_eq!(format!("{}", apdex.score_rating()), "Fair [4.0]");
     }
+
+    #[test]
+    fn standard_error_no_samples() {
+        let apdex = Apdex::default();
+        assert!(apdex.standard_error().is_none());
+        assert!(apdex.confidence_interval(0.95).is_none());
+    }
+
+    #[test]
+    fn standard_error_and_confidence_interval() {
+        let mut apdex = Apdex::new(1.0);
+        for _i in 0..50 {
+            apdex.insert(Ok(0.1));
+        }
+        for _i in 0..50 {
+            apdex.insert(Ok(5.0));
+        }
+        assert_eq!(apdex.score().unwrap(), 0.5);
+
+        let se = apdex.standard_error().unwrap();
+        assert!((se - 0.05).abs() < 0.001);
+
+        let (lo, hi) = apdex.confidence_interval(0.95).unwrap();
+        assert!((lo - (0.5 - 1.96 * se)).abs() < 0.001);
+        assert!((hi - (0.5 + 1.96 * se)).abs() < 0.001);
+    }
+
+    #[test]
+    fn confidence_interval_clamped() {
+        let mut apdex = Apdex::default();
+        for _i in 0..100 {
+            apdex.insert(Ok(0.1));
+        }
+        let (lo, hi) = apdex.confidence_interval(0.99).unwrap();
+        assert_eq!(lo, 1.0);
+        assert_eq!(hi, 1.0);
+    }
+
+    #[test]
+    fn custom_tolerating_multiplier() {
+        let config = ApdexConfig::new(2.0, default_bands());
+        let mut apdex = Apdex::with_config(1.0, config);
+        apdex.insert(Ok(1.5));
+        apdex.insert(Ok(2.5));
+        assert_eq!(apdex.tolerating, 1);
+        assert_eq!(apdex.frustrated, 1);
+    }
+
+    #[cfg(feature = "yansi")]
+    fn test_band(min_score: f64, word: &'static str) -> RatingBand {
+        RatingBand::new(min_score, word, Color::Unset)
+    }
+
+    #[cfg(not(feature = "yansi"))]
+    fn test_band(min_score: f64, word: &'static str) -> RatingBand {
+        RatingBand::new(min_score, word)
+    }
+
+    #[test]
+    fn custom_rating_bands() {
+        let bands = vec![
+            test_band(0.90, "Great"),
+            test_band(0.0, "Meh"),
+        ];
+        let config = ApdexConfig::new(4.0, bands);
+        let mut apdex = Apdex::with_config(1.0, config);
+        for _i in 0..100 {
+            apdex.insert(Ok(0.1));
+        }
+        assert_eq!(apdex.rating_word(), "Great");
+
+        let mut apdex = Apdex::with_config(1.0, ApdexConfig::new(4.0, vec![
+            test_band(0.90, "Great"),
+            test_band(0.0, "Meh"),
+        ]));
+        for _i in 0..100 {
+            apdex.insert(Ok(5.0));
+        }
+        assert_eq!(apdex.rating_word(), "Meh");
+    }
+
+    #[test]
+    #[should_panic(expected = "tolerating multipliers")]
+    #[cfg(debug_assertions)]
+    fn merge_panics_on_mismatched_tolerating_multiplier() {
+        let mut a = Apdex::with_config(1.0, ApdexConfig::new(2.0, default_bands()));
+        let mut b = Apdex::with_config(1.0, ApdexConfig::new(10.0, default_bands()));
+        a.insert(Ok(1.5));
+        b.insert(Ok(5.0));
+
+        a.merge(&b);
+    }
+
+    #[test]
+    fn merge_sums_counts() {
+        let mut a = Apdex::new(1.0);
+        a.insert(Ok(0.1));
+        a.insert(Ok(5.0));
+        let mut b = Apdex::new(1.0);
+        b.insert(Ok(0.1));
+
+        a.merge(&b);
+        assert_eq!(a.satisfied, 2);
+        assert_eq!(a.frustrated, 1);
+        assert_eq!(a.total(), 3);
+    }
+
+    #[test]
+    fn add_combines_two_apdex() {
+        let mut a = Apdex::new(1.0);
+        a.insert(Ok(0.1));
+        let mut b = Apdex::new(1.0);
+        b.insert(Ok(5.0));
+
+        let combined = a + b;
+        assert_eq!(combined.satisfied, 1);
+        assert_eq!(combined.frustrated, 1);
+    }
+
+    #[test]
+    fn add_assign_merges_in_place() {
+        let mut a = Apdex::new(1.0);
+        a.insert(Ok(0.1));
+        let mut b = Apdex::new(1.0);
+        b.insert(Ok(5.0));
+
+        a += &b;
+        assert_eq!(a.satisfied, 1);
+        assert_eq!(a.frustrated, 1);
+    }
+
+    #[test]
+    fn from_iter_combines_shards() {
+        let shards = (0..3).map(|_| {
+            let mut shard = Apdex::new(1.0);
+            shard.insert(Ok(0.1));
+            shard
+        });
+
+        let combined: Apdex = shards.collect();
+        assert_eq!(combined.satisfied, 3);
+        assert_eq!(combined.total(), 3);
+    }
+
+    #[test]
+    fn compare_regression() {
+        let mut baseline = Apdex::new(1.0);
+        for _i in 0..1000 {
+            baseline.insert(Ok(0.1));
+        }
+        let mut new = Apdex::new(1.0);
+        for _i in 0..500 {
+            new.insert(Ok(0.1));
+        }
+        for _i in 0..500 {
+            new.insert(Ok(5.0));
+        }
+
+        match new.compare(&baseline) {
+            Comparison::Regression { delta, from, to } => {
+                assert!(delta < 0.0);
+                assert_eq!(from, "Excellent");
+                assert_eq!(to, "Poor");
+            }
+            other => panic!("expected Regression, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compare_improvement() {
+        let mut baseline = Apdex::new(1.0);
+        for _i in 0..500 {
+            baseline.insert(Ok(0.1));
+        }
+        for _i in 0..500 {
+            baseline.insert(Ok(5.0));
+        }
+        let mut new = Apdex::new(1.0);
+        for _i in 0..1000 {
+            new.insert(Ok(0.1));
+        }
+
+        match new.compare(&baseline) {
+            Comparison::Improvement { delta, .. } => assert!(delta > 0.0),
+            other => panic!("expected Improvement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compare_no_change() {
+        let mut baseline = Apdex::new(1.0);
+        for _i in 0..50 {
+            baseline.insert(Ok(0.1));
+        }
+        let mut new = Apdex::new(1.0);
+        for _i in 0..50 {
+            new.insert(Ok(0.1));
+        }
+        for _i in 0..1 {
+            new.insert(Ok(5.0));
+        }
+
+        match new.compare(&baseline) {
+            Comparison::NoChange { .. } => {}
+            other => panic!("expected NoChange, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn comparison_display() {
+        let comparison = Comparison::Regression { delta: -0.1, from: "Good", to: "Fair" };
+        assert_eq!(format!("{}", comparison), "Regression: -0.10 (\"Good\" → \"Fair\")");
+    }
+
+    #[test]
+    fn summary_no_samples() {
+        let summary = ApdexSummary::new(1.0);
+        assert!(summary.mean().is_none());
+        assert!(summary.std_dev().is_none());
+        assert!(summary.min().is_none());
+        assert!(summary.max().is_none());
+        assert!(summary.median().is_none());
+        assert!(summary.percentile(0.9).is_none());
+    }
+
+    #[test]
+    fn summary_mean_min_max() {
+        let summary = ApdexSummary::with_samples(1.0, [0.0, 0.1, 0.2, 0.5, 1.0].iter().cloned().map(Ok));
+        assert_eq!(summary.mean().unwrap(), 0.36);
+        assert_eq!(summary.min().unwrap(), 0.0);
+        assert_eq!(summary.max().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn summary_median() {
+        let summary = ApdexSummary::with_samples(1.0, [1.0, 3.0, 2.0, 4.0].iter().cloned().map(Ok));
+        assert_eq!(summary.median().unwrap(), 2.5);
+    }
+
+    #[test]
+    fn summary_percentile() {
+        let summary = ApdexSummary::with_samples(1.0, [1.0, 2.0, 3.0, 4.0, 5.0].iter().cloned().map(Ok));
+        assert_eq!(summary.percentile(0.0).unwrap(), 1.0);
+        assert_eq!(summary.percentile(1.0).unwrap(), 5.0);
+        assert_eq!(summary.percentile(0.5).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn summary_percentile_clamps_out_of_range_q() {
+        let summary = ApdexSummary::with_samples(1.0, [1.0, 2.0, 3.0, 4.0, 5.0].iter().cloned().map(Ok));
+        assert_eq!(summary.percentile(95.0).unwrap(), 5.0);
+        assert_eq!(summary.percentile(-1.0).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn summary_errors_counted_but_not_retained() {
+        let summary = ApdexSummary::with_samples(1.0, [Ok(0.1), Ok(0.2), Err(())].iter().cloned());
+        assert_eq!(summary.apdex.total(), 3);
+        assert_eq!(summary.apdex.frustrated, 1);
+        assert_eq!(summary.mean().unwrap(), 0.15000000000000002);
+    }
 }